@@ -7,5 +7,7 @@ use pyo3::prelude::*;
 #[pymodule]
 fn linalg_lib(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<SprsMat>()?;
+    m.add_class::<CscMat>()?;
+    m.add_class::<SprsMatIter>()?;
     Ok(())
 }
@@ -1,7 +1,19 @@
-use pyo3::{exceptions::PyIndexError, prelude::*};
+// pyo3's #[pymethods]/#[staticmethod] expansion generates a hidden trampoline
+// for each fallible method that re-wraps its `PyResult` through a conversion
+// clippy sees as a no-op; the lint attributes to that generated code rather
+// than the method it's derived from, so a per-method or per-impl `#[allow]`
+// doesn't reach it. Silenced module-wide until pyo3/clippy resolve the
+// interaction upstream.
+#![allow(clippy::useless_conversion)]
+
+use numpy::{PyArray2, PyArrayMethods, PyReadonlyArray2};
+use pyo3::{
+    exceptions::{PyIndexError, PyValueError},
+    prelude::*,
+};
 
 #[pyclass]
-#[derive(Default)]
+#[derive(Default, Debug)]
 pub struct SprsMat {
     pub row_ptr: Vec<usize>,
     pub col_indx: Vec<usize>,
@@ -10,6 +22,65 @@ pub struct SprsMat {
     pub shape: (usize, usize),
 }
 
+/// A column-major sparse matrix: `col_ptr[j]..col_ptr[j+1]` indexes into
+/// `row_indx`/`vals` for the entries stored in column `j`.
+#[pyclass]
+#[derive(Default)]
+pub struct CscMat {
+    #[pyo3(get)]
+    pub col_ptr: Vec<usize>,
+    #[pyo3(get)]
+    pub row_indx: Vec<usize>,
+    vals: Vec<f64>,
+    #[pyo3(get)]
+    pub shape: (usize, usize),
+}
+
+#[pymethods]
+impl CscMat {
+    pub fn vals(&self) -> Vec<f64> {
+        self.vals.clone()
+    }
+}
+
+/// Iterates a `SprsMat`'s stored entries as `(row, col, value)` triplets in
+/// CSR order, giving O(nnz) traversal instead of an O(m*n) scan.
+#[pyclass]
+pub struct SprsMatIter {
+    row_ptr: Vec<usize>,
+    col_indx: Vec<usize>,
+    vals: Vec<f64>,
+    row: usize,
+    pos: usize,
+}
+
+impl Iterator for SprsMatIter {
+    type Item = (usize, usize, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.row + 1 < self.row_ptr.len() {
+            if self.pos < self.row_ptr[self.row + 1] {
+                let entry = (self.row, self.col_indx[self.pos], self.vals[self.pos]);
+                self.pos += 1;
+                return Some(entry);
+            }
+            self.row += 1;
+        }
+        None
+    }
+}
+
+#[pymethods]
+impl SprsMatIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<(usize, usize, f64)> {
+        slf.next()
+    }
+}
+
 #[pymethods]
 impl SprsMat {
     #[new]
@@ -36,10 +107,374 @@ impl SprsMat {
         }
     }
 
+    /// Build a CSR matrix from parallel COO triplets using a counting sort:
+    /// histogram entries per row into `row_ptr`, scatter each triplet into
+    /// its row's slice, then sort and dedup (summing) within each row.
+    #[staticmethod]
+    pub fn from_triplets(
+        rows: Vec<usize>,
+        cols: Vec<usize>,
+        vals: Vec<f64>,
+        shape: (usize, usize),
+    ) -> PyResult<Self> {
+        if rows.len() != cols.len() || rows.len() != vals.len() {
+            return Err(PyValueError::new_err(
+                "rows, cols and vals must have the same length",
+            ));
+        }
+        if rows.iter().any(|&r| r >= shape.0) || cols.iter().any(|&c| c >= shape.1) {
+            return Err(PyValueError::new_err(
+                "triplet index out of bounds for the given shape",
+            ));
+        }
+
+        let mut row_ptr = vec![0usize; shape.0 + 1];
+        for &r in &rows {
+            row_ptr[r + 1] += 1;
+        }
+        for i in 0..shape.0 {
+            row_ptr[i + 1] += row_ptr[i];
+        }
+
+        let nnz = rows.len();
+        let mut col_indx = vec![0usize; nnz];
+        let mut raw_vals = vec![0.0f64; nnz];
+        let mut cursor = row_ptr.clone();
+        for k in 0..nnz {
+            let r = rows[k];
+            let dest = cursor[r];
+            col_indx[dest] = cols[k];
+            raw_vals[dest] = vals[k];
+            cursor[r] += 1;
+        }
+
+        let mut mat = SprsMat {
+            row_ptr,
+            col_indx,
+            vals: raw_vals,
+            shape,
+        };
+        mat.canonicalize();
+        Ok(mat)
+    }
+
+    /// Build a CSR matrix from raw arrays, rejecting anything that is not a
+    /// well-formed CSR structure instead of silently producing garbage.
+    #[staticmethod]
+    pub fn from_csr_checked(
+        row_ptr: Vec<usize>,
+        col_indx: Vec<usize>,
+        vals: Vec<f64>,
+        shape: (usize, usize),
+    ) -> PyResult<Self> {
+        if row_ptr.len() != shape.0 + 1 {
+            return Err(PyValueError::new_err(format!(
+                "row_ptr has length {} but shape {:?} requires {}",
+                row_ptr.len(),
+                shape,
+                shape.0 + 1
+            )));
+        }
+        if row_ptr[0] != 0 {
+            return Err(PyValueError::new_err("row_ptr must start at 0"));
+        }
+        if row_ptr.windows(2).any(|w| w[0] > w[1]) {
+            return Err(PyValueError::new_err(
+                "row_ptr must be monotonically non-decreasing",
+            ));
+        }
+        let nnz = row_ptr[shape.0];
+        if nnz != col_indx.len() || nnz != vals.len() {
+            return Err(PyValueError::new_err(format!(
+                "row_ptr[-1] ({}) must match col_indx.len() ({}) and vals.len() ({})",
+                nnz,
+                col_indx.len(),
+                vals.len()
+            )));
+        }
+
+        for i in 0..shape.0 {
+            let row = &col_indx[row_ptr[i]..row_ptr[i + 1]];
+            if row.iter().any(|&c| c >= shape.1) {
+                return Err(PyValueError::new_err(format!(
+                    "row {} has a column index out of bounds for shape {:?}",
+                    i, shape
+                )));
+            }
+            if row.windows(2).any(|w| w[0] >= w[1]) {
+                return Err(PyValueError::new_err(format!(
+                    "row {} is not strictly increasing in column index",
+                    i
+                )));
+            }
+        }
+
+        Ok(SprsMat {
+            row_ptr,
+            col_indx,
+            vals,
+            shape,
+        })
+    }
+
+    /// Sort each row by column index, sum duplicate `(row, col)` entries and
+    /// drop explicit zeros, repairing a matrix that was built out of order.
+    pub fn canonicalize(&mut self) {
+        let mut new_row_ptr = Vec::with_capacity(self.row_ptr.len());
+        let mut new_col_indx = Vec::with_capacity(self.col_indx.len());
+        let mut new_vals = Vec::with_capacity(self.vals.len());
+        new_row_ptr.push(0);
+
+        for i in 0..self.shape.0 {
+            let start = self.row_ptr[i];
+            let end = self.row_ptr[i + 1];
+            let mut row: Vec<(usize, f64)> = self.col_indx[start..end]
+                .iter()
+                .copied()
+                .zip(self.vals[start..end].iter().copied())
+                .collect();
+            row.sort_by_key(|&(c, _)| c);
+
+            let row_start = new_col_indx.len();
+            for (col, val) in row {
+                match new_col_indx.last() {
+                    Some(&last_col) if new_col_indx.len() > row_start && last_col == col => {
+                        *new_vals.last_mut().unwrap() += val;
+                    }
+                    _ => {
+                        new_col_indx.push(col);
+                        new_vals.push(val);
+                    }
+                }
+            }
+            new_row_ptr.push(new_col_indx.len());
+        }
+
+        // Drop explicit zeros in a single pass: recompute each row's count of
+        // surviving entries, prefix-sum into a fresh row_ptr, then compact
+        // col_indx/vals with retain. Avoids the O(nnz) shift per removal
+        // that repeated `Vec::remove` would cost.
+        let mut kept_row_ptr = vec![0usize; new_row_ptr.len()];
+        for i in 0..self.shape.0 {
+            let nonzero_in_row = new_vals[new_row_ptr[i]..new_row_ptr[i + 1]]
+                .iter()
+                .filter(|&&v| v != 0.0)
+                .count();
+            kept_row_ptr[i + 1] = kept_row_ptr[i] + nonzero_in_row;
+        }
+
+        let mut kept = new_vals.iter().map(|&v| v != 0.0);
+        new_col_indx.retain(|_| kept.next().unwrap());
+        new_vals.retain(|&v| v != 0.0);
+
+        self.row_ptr = kept_row_ptr;
+        self.col_indx = new_col_indx;
+        self.vals = new_vals;
+    }
+
+    /// Scan a dense NumPy array row by row, emitting the CSR arrays for its
+    /// nonzero entries.
+    #[staticmethod]
+    pub fn from_dense(dense: PyReadonlyArray2<f64>) -> Self {
+        let dense = dense.as_array();
+        let shape = (dense.nrows(), dense.ncols());
+
+        let mut row_ptr = Vec::with_capacity(shape.0 + 1);
+        let mut col_indx = Vec::new();
+        let mut vals = Vec::new();
+        row_ptr.push(0);
+
+        for row in dense.rows() {
+            for (j, &v) in row.iter().enumerate() {
+                if v != 0.0 {
+                    col_indx.push(j);
+                    vals.push(v);
+                }
+            }
+            row_ptr.push(col_indx.len());
+        }
+
+        SprsMat {
+            row_ptr,
+            col_indx,
+            vals,
+            shape,
+        }
+    }
+
+    /// Allocate an `m x n` zero array and scatter the stored values back
+    /// into it.
+    pub fn to_dense<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f64>> {
+        let dense = PyArray2::zeros_bound(py, self.shape, false);
+        for i in 0..self.shape.0 {
+            for k in self.row_ptr[i]..self.row_ptr[i + 1] {
+                unsafe {
+                    *dense.get_mut([i, self.col_indx[k]]).unwrap() = self.vals[k];
+                }
+            }
+        }
+        dense
+    }
+
     pub fn vals(&self) -> Vec<f64> {
         self.vals.clone()
     }
 
+    /// Convert to column-major storage using the standard counting
+    /// algorithm: count nonzeros per column into `col_ptr`, prefix-sum it,
+    /// then walk the CSR rows scattering each entry at the per-column
+    /// cursor.
+    pub fn to_csc(&self) -> CscMat {
+        let (m, n) = self.shape;
+
+        let mut col_ptr = vec![0usize; n + 1];
+        for &c in &self.col_indx {
+            col_ptr[c + 1] += 1;
+        }
+        for j in 0..n {
+            col_ptr[j + 1] += col_ptr[j];
+        }
+
+        let nnz = self.vals.len();
+        let mut row_indx = vec![0usize; nnz];
+        let mut vals = vec![0.0f64; nnz];
+        let mut cursor = col_ptr.clone();
+        for i in 0..m {
+            for k in self.row_ptr[i]..self.row_ptr[i + 1] {
+                let j = self.col_indx[k];
+                let dest = cursor[j];
+                row_indx[dest] = i;
+                vals[dest] = self.vals[k];
+                cursor[j] += 1;
+            }
+        }
+
+        CscMat {
+            col_ptr,
+            row_indx,
+            vals,
+            shape: (m, n),
+        }
+    }
+
+    /// Transpose the matrix. CSR-to-CSC of `A` is exactly CSR of `A^T`, so
+    /// this reuses `to_csc` and just relabels the result as row-major.
+    pub fn transpose(&self) -> SprsMat {
+        let csc = self.to_csc();
+        SprsMat {
+            row_ptr: csc.col_ptr,
+            col_indx: csc.row_indx,
+            vals: csc.vals,
+            shape: (self.shape.1, self.shape.0),
+        }
+    }
+
+    /// Compute `y[i] = sum_k vals[k] * x[col_indx[k]]` over each row's slice.
+    pub fn matvec(&self, x: Vec<f64>) -> PyResult<Vec<f64>> {
+        if x.len() != self.shape.1 {
+            return Err(PyValueError::new_err(format!(
+                "cannot multiply {:?} matrix by vector of length {}",
+                self.shape,
+                x.len()
+            )));
+        }
+
+        let mut y = vec![0.0; self.shape.0];
+        for (i, y_i) in y.iter_mut().enumerate() {
+            let mut acc = 0.0;
+            for k in self.row_ptr[i]..self.row_ptr[i + 1] {
+                acc += self.vals[k] * x[self.col_indx[k]];
+            }
+            *y_i = acc;
+        }
+        Ok(y)
+    }
+
+    /// Sparse matrix-matrix product using Gustavson's row-by-row algorithm:
+    /// for each row of `self`, accumulate into a dense row-length buffer
+    /// plus a marker array tracking which columns were touched, then gather
+    /// the marked columns into the output row.
+    pub fn matmul(&self, other: &SprsMat) -> PyResult<SprsMat> {
+        if self.shape.1 != other.shape.0 {
+            return Err(PyValueError::new_err(format!(
+                "cannot multiply {:?} matrix by {:?} matrix",
+                self.shape, other.shape
+            )));
+        }
+        let (m, n) = (self.shape.0, other.shape.1);
+
+        let mut row_ptr = Vec::with_capacity(m + 1);
+        let mut col_indx = Vec::new();
+        let mut vals = Vec::new();
+        row_ptr.push(0);
+
+        let mut acc = vec![0.0f64; n];
+        let mut touched = vec![false; n];
+        let mut marked = Vec::new();
+
+        for i in 0..self.shape.0 {
+            for k in self.row_ptr[i]..self.row_ptr[i + 1] {
+                let a_ik = self.vals[k];
+                let k_row = self.col_indx[k];
+                for t in other.row_ptr[k_row]..other.row_ptr[k_row + 1] {
+                    let j = other.col_indx[t];
+                    if !touched[j] {
+                        touched[j] = true;
+                        marked.push(j);
+                    }
+                    acc[j] += a_ik * other.vals[t];
+                }
+            }
+
+            marked.sort_unstable();
+            for &j in &marked {
+                col_indx.push(j);
+                vals.push(acc[j]);
+                acc[j] = 0.0;
+                touched[j] = false;
+            }
+            marked.clear();
+            row_ptr.push(col_indx.len());
+        }
+
+        Ok(SprsMat {
+            row_ptr,
+            col_indx,
+            vals,
+            shape: (m, n),
+        })
+    }
+
+    /// Number of explicitly stored entries.
+    pub fn nnz(&self) -> usize {
+        self.vals.len()
+    }
+
+    /// Parallel `(col_indx, vals)` slices for row `i`, for O(nnz) traversal
+    /// without going through `__getitem__` per column.
+    pub fn row(&self, i: i32) -> PyResult<(Vec<usize>, Vec<f64>)> {
+        let i =
+            usize::try_from(i).map_err(|_| PyIndexError::new_err("index i cannot be negative"))?;
+        if i >= self.shape.0 {
+            return Err(PyIndexError::new_err(format!(
+                "row index {} out of bounds for matrix of shape {:?}",
+                i, self.shape
+            )));
+        }
+        let range = self.row_ptr[i]..self.row_ptr[i + 1];
+        Ok((self.col_indx[range.clone()].to_vec(), self.vals[range].to_vec()))
+    }
+
+    fn __iter__(&self) -> SprsMatIter {
+        SprsMatIter {
+            row_ptr: self.row_ptr.clone(),
+            col_indx: self.col_indx.clone(),
+            vals: self.vals.clone(),
+            row: 0,
+            pos: 0,
+        }
+    }
+
     // fn shape(&self) -> (usize, usize) {
     //     self.shape
     // }
@@ -103,3 +538,221 @@ impl SprsMat {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_csr_checked_rejects_wrong_row_ptr_len() {
+        let err = SprsMat::from_csr_checked(vec![0, 1], vec![0], vec![1.0], (2, 2)).unwrap_err();
+        assert!(Python::with_gil(|py| err.is_instance_of::<PyValueError>(py)));
+    }
+
+    #[test]
+    fn from_csr_checked_rejects_row_ptr_not_starting_at_zero() {
+        let err =
+            SprsMat::from_csr_checked(vec![1, 1, 1], vec![], vec![], (2, 2)).unwrap_err();
+        assert!(Python::with_gil(|py| err.is_instance_of::<PyValueError>(py)));
+    }
+
+    #[test]
+    fn from_csr_checked_rejects_non_monotonic_row_ptr() {
+        let err =
+            SprsMat::from_csr_checked(vec![0, 2, 1], vec![0, 1], vec![1.0, 2.0], (2, 2))
+                .unwrap_err();
+        assert!(Python::with_gil(|py| err.is_instance_of::<PyValueError>(py)));
+    }
+
+    #[test]
+    fn from_csr_checked_rejects_mismatched_nnz() {
+        let err = SprsMat::from_csr_checked(vec![0, 1, 1], vec![0, 1], vec![1.0], (2, 2))
+            .unwrap_err();
+        assert!(Python::with_gil(|py| err.is_instance_of::<PyValueError>(py)));
+    }
+
+    #[test]
+    fn from_csr_checked_rejects_out_of_bounds_column() {
+        let err =
+            SprsMat::from_csr_checked(vec![0, 1], vec![5], vec![1.0], (1, 2)).unwrap_err();
+        assert!(Python::with_gil(|py| err.is_instance_of::<PyValueError>(py)));
+    }
+
+    #[test]
+    fn from_csr_checked_rejects_unsorted_row() {
+        let err = SprsMat::from_csr_checked(vec![0, 2], vec![1, 0], vec![1.0, 2.0], (1, 2))
+            .unwrap_err();
+        assert!(Python::with_gil(|py| err.is_instance_of::<PyValueError>(py)));
+    }
+
+    #[test]
+    fn from_csr_checked_accepts_well_formed_input() {
+        let mat =
+            SprsMat::from_csr_checked(vec![0, 1, 2], vec![1, 0], vec![1.0, 2.0], (2, 2)).unwrap();
+        assert_eq!(mat.vals(), vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn canonicalize_sums_duplicates_and_drops_explicit_zeros() {
+        let mut mat = SprsMat::new(
+            vec![0, 4],
+            vec![1, 0, 1, 0],
+            vec![2.0, 5.0, -2.0, 0.0],
+            (1, 2),
+        );
+        mat.canonicalize();
+        assert_eq!(mat.row_ptr, vec![0, 1]);
+        assert_eq!(mat.col_indx, vec![0]);
+        assert_eq!(mat.vals(), vec![5.0]);
+    }
+
+    #[test]
+    fn from_triplets_sorts_rows_and_sums_duplicates() {
+        // Row 0 out of order, row 1 has a duplicate coordinate that sums to
+        // an explicit zero and should be dropped by canonicalize.
+        let mat = SprsMat::from_triplets(
+            vec![0, 0, 1, 1],
+            vec![1, 0, 0, 0],
+            vec![2.0, 5.0, 3.0, -3.0],
+            (2, 2),
+        )
+        .unwrap();
+
+        assert_eq!(mat.row_ptr, vec![0, 2, 2]);
+        assert_eq!(mat.col_indx, vec![0, 1]);
+        assert_eq!(mat.vals(), vec![5.0, 2.0]);
+    }
+
+    #[test]
+    fn from_triplets_rejects_out_of_bounds_index() {
+        let err = SprsMat::from_triplets(vec![0], vec![5], vec![1.0], (1, 2)).unwrap_err();
+        assert!(Python::with_gil(|py| err.is_instance_of::<PyValueError>(py)));
+    }
+
+    #[test]
+    fn from_triplets_rejects_mismatched_lengths() {
+        let err = SprsMat::from_triplets(vec![0, 1], vec![0], vec![1.0], (2, 2)).unwrap_err();
+        assert!(Python::with_gil(|py| err.is_instance_of::<PyValueError>(py)));
+    }
+
+    /// A 2x3 asymmetric matrix:
+    /// [ 1 0 2 ]
+    /// [ 0 3 0 ]
+    fn asymmetric_2x3() -> SprsMat {
+        SprsMat::new(vec![0, 2, 3], vec![0, 2, 1], vec![1.0, 2.0, 3.0], (2, 3))
+    }
+
+    #[test]
+    fn to_csc_matches_column_major_layout() {
+        let csc = asymmetric_2x3().to_csc();
+        assert_eq!(csc.shape, (2, 3));
+        assert_eq!(csc.col_ptr, vec![0, 1, 2, 3]);
+        assert_eq!(csc.row_indx, vec![0, 1, 0]);
+        assert_eq!(csc.vals(), vec![1.0, 3.0, 2.0]);
+    }
+
+    #[test]
+    fn transpose_round_trips_through_to_csc() {
+        let transposed = asymmetric_2x3().transpose();
+        assert_eq!(transposed.shape, (3, 2));
+        assert_eq!(transposed.row_ptr, vec![0, 1, 2, 3]);
+        assert_eq!(transposed.col_indx, vec![0, 1, 0]);
+        assert_eq!(transposed.vals(), vec![1.0, 3.0, 2.0]);
+
+        // Transposing back should recover the original matrix.
+        let round_tripped = transposed.transpose();
+        assert_eq!(round_tripped.row_ptr, vec![0, 2, 3]);
+        assert_eq!(round_tripped.col_indx, vec![0, 2, 1]);
+        assert_eq!(round_tripped.vals(), vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn matvec_computes_row_dot_products() {
+        // [ 1 0 2 ]   [1]   [ 1*1 + 2*3 ]   [7]
+        // [ 0 3 0 ] * [1] = [    3*1    ] = [3]
+        //             [3]
+        let y = asymmetric_2x3().matvec(vec![1.0, 1.0, 3.0]).unwrap();
+        assert_eq!(y, vec![7.0, 3.0]);
+    }
+
+    #[test]
+    fn matvec_rejects_mismatched_length() {
+        let err = asymmetric_2x3().matvec(vec![1.0, 2.0]).unwrap_err();
+        assert!(Python::with_gil(|py| err.is_instance_of::<PyValueError>(py)));
+    }
+
+    #[test]
+    fn matmul_matches_hand_computed_product() {
+        // A (2x3) is asymmetric_2x3. B (3x2):
+        // [ 1 0 ]
+        // [ 0 1 ]
+        // [ 2 0 ]
+        // A*B = [ 1*1 + 2*2  0 ]   [ 5 0 ]
+        //       [     0      3 ] = [ 0 3 ]
+        let a = asymmetric_2x3();
+        let b = SprsMat::new(vec![0, 1, 2, 3], vec![0, 1, 0], vec![1.0, 1.0, 2.0], (3, 2));
+
+        let c = a.matmul(&b).unwrap();
+        assert_eq!(c.shape, (2, 2));
+        assert_eq!(c.row_ptr, vec![0, 1, 2]);
+        assert_eq!(c.col_indx, vec![0, 1]);
+        assert_eq!(c.vals(), vec![5.0, 3.0]);
+    }
+
+    #[test]
+    fn matmul_rejects_inner_dimension_mismatch() {
+        let a = asymmetric_2x3();
+        let b = SprsMat::zeros(2, 2);
+        let err = a.matmul(&b).unwrap_err();
+        assert!(Python::with_gil(|py| err.is_instance_of::<PyValueError>(py)));
+    }
+
+    #[test]
+    fn nnz_counts_stored_entries() {
+        assert_eq!(asymmetric_2x3().nnz(), 3);
+        assert_eq!(SprsMat::zeros(3, 3).nnz(), 0);
+    }
+
+    #[test]
+    fn row_returns_the_requested_slice() {
+        let mat = asymmetric_2x3();
+        assert_eq!(mat.row(0).unwrap(), (vec![0, 2], vec![1.0, 2.0]));
+        assert_eq!(mat.row(1).unwrap(), (vec![1], vec![3.0]));
+    }
+
+    #[test]
+    fn row_rejects_negative_index() {
+        let err = asymmetric_2x3().row(-1).unwrap_err();
+        assert!(Python::with_gil(|py| err.is_instance_of::<PyIndexError>(py)));
+    }
+
+    #[test]
+    fn row_rejects_out_of_bounds_index() {
+        let err = asymmetric_2x3().row(2).unwrap_err();
+        assert!(Python::with_gil(|py| err.is_instance_of::<PyIndexError>(py)));
+    }
+
+    #[test]
+    fn iter_yields_csr_ordered_triplets() {
+        let triplets: Vec<_> = asymmetric_2x3().__iter__().collect();
+        assert_eq!(triplets, vec![(0, 0, 1.0), (0, 2, 2.0), (1, 1, 3.0)]);
+    }
+
+    #[test]
+    fn from_dense_to_dense_round_trips() {
+        Python::with_gil(|py| {
+            let data = vec![vec![1.0, 0.0, 2.0], vec![0.0, 3.0, 0.0]];
+            let dense = PyArray2::from_vec2_bound(py, &data).unwrap();
+
+            let mat = SprsMat::from_dense(dense.readonly());
+            assert_eq!(mat.row_ptr, vec![0, 2, 3]);
+            assert_eq!(mat.col_indx, vec![0, 2, 1]);
+            assert_eq!(mat.vals(), vec![1.0, 2.0, 3.0]);
+
+            let round_tripped = mat.to_dense(py).to_owned_array();
+            let round_tripped: Vec<Vec<f64>> =
+                round_tripped.outer_iter().map(|row| row.to_vec()).collect();
+            assert_eq!(round_tripped, data);
+        });
+    }
+}